@@ -0,0 +1,124 @@
+// Parallel directory batch processing. Every file in the input directory is
+// processed through the same `ModelManager`, shared across threads behind
+// an `Arc<Mutex<_>>` so the expensive ONNX session is loaded once rather
+// than per file. The file set is split across threads with `rayon`; each
+// rayon job keeps its own `Resizer` (via `map_init`) so consecutive
+// same-sized images reuse its precomputed Lanczos3 taps instead of
+// rebuilding them per file.
+
+use crate::compress_png::{compress_png_16, CompressionOptions};
+use crate::error::RembgError;
+use crate::formats::{self, OutputFormat};
+use crate::manager::ModelManager;
+use crate::options::RemovalOptions;
+use crate::rembg::rembg_with_resizer;
+use crate::resize::Resizer;
+use image::DynamicImage;
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+const MODEL_INPUT_SIZE: u32 = 320;
+
+/// Output settings for a batch run, mirroring the single-image CLI's
+/// `--format`/`--background`/compression flags so batch mode honors them
+/// too instead of always writing default-settings PNG.
+pub struct BatchOutput {
+    pub format: OutputFormat,
+    pub background: [u8; 3],
+    pub compression: CompressionOptions,
+}
+
+impl Default for BatchOutput {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::Png,
+            background: [255, 255, 255],
+            compression: CompressionOptions::default(),
+        }
+    }
+}
+
+/// Process every file in `input_dir`, writing results to `output_dir` under
+/// the same file stem, encoded per `output`. Returns one result per input
+/// file — a single bad image doesn't abort the rest of the batch.
+pub fn process_directory(
+    manager: Arc<Mutex<ModelManager>>,
+    input_dir: &Path,
+    output_dir: &Path,
+    options: &RemovalOptions,
+    output: &BatchOutput,
+) -> Result<Vec<(PathBuf, Result<(), RembgError>)>, RembgError> {
+    std::fs::create_dir_all(output_dir)?;
+
+    let entries: Vec<PathBuf> = std::fs::read_dir(input_dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+
+    let results = entries
+        .par_iter()
+        .map_init(
+            || None::<Resizer>,
+            |resizer_cache, path| {
+                let result = process_one(&manager, resizer_cache, path, output_dir, options, output);
+                (path.clone(), result)
+            },
+        )
+        .collect();
+
+    Ok(results)
+}
+
+fn process_one(
+    manager: &Arc<Mutex<ModelManager>>,
+    resizer_cache: &mut Option<Resizer>,
+    input_path: &Path,
+    output_dir: &Path,
+    options: &RemovalOptions,
+    output: &BatchOutput,
+) -> Result<(), RembgError> {
+    let image = image::open(input_path)?;
+    let (width, height) = (image.width(), image.height());
+
+    let resizer = match resizer_cache {
+        Some(resizer) if resizer.src_dims() == (width, height) => resizer,
+        _ => {
+            *resizer_cache = Some(Resizer::new(width, height, MODEL_INPUT_SIZE, MODEL_INPUT_SIZE));
+            resizer_cache.as_mut().unwrap()
+        }
+    };
+
+    let result = {
+        let mut manager = manager
+            .lock()
+            .map_err(|_| RembgError::LockPoisoned("model manager poisoned".to_string()))?;
+        rembg_with_resizer(&mut manager, image, options, resizer)?
+    };
+
+    let file_name = input_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| RembgError::InvalidInput("input path has no file name".to_string()))?;
+    let extension = match output.format {
+        OutputFormat::Png => "png",
+        OutputFormat::WebP { .. } => "webp",
+        OutputFormat::Jpeg => "jpg",
+    };
+    let output_path = output_dir.join(file_name).with_extension(extension);
+
+    // Preserve full color depth for 16-bit sources, matching the
+    // single-image CLI path, instead of always flattening to 8-bit. Only
+    // the PNG path can represent it.
+    let bytes = match (result.image16(), output.format) {
+        (Some(image16), OutputFormat::Png) => compress_png_16(image16, &output.compression)?,
+        _ => {
+            let out_img = DynamicImage::ImageRgba8(result.image().clone());
+            formats::encode(&out_img, output.format, output.background, &output.compression)?
+        }
+    };
+    std::fs::write(&output_path, bytes)?;
+
+    Ok(())
+}
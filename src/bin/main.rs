@@ -1,23 +1,51 @@
 use clap::Parser;
 use image::{DynamicImage, open};
-use rembg_rs::cli::cli::Args;
-use rembg_rs::compress_png::compress_png;
-use rembg_rs::manager::ModelManager;
+use rembg_rs::cli::cli::{Args, Command};
+use rembg_rs::compress_png::{CompressionOptions, compress_png_16};
+use rembg_rs::formats::{self, OutputFormat};
+use rembg_rs::manager::{ExecutionProvider, ModelManager};
 use rembg_rs::options::RemovalOptionsBuilder;
 use rembg_rs::rembg::rembg;
+use rembg_rs::server;
 use std::path::Path;
 use std::process;
+use std::sync::{Arc, Mutex};
 
 fn main() {
     let args = Args::parse();
 
+    if let Some(Command::Serve { bind, port }) = &args.command {
+        println!("🌐 rembg-rs - starting server on {}:{}", bind, port);
+        let device = parse_device(&args.device);
+        if let Err(e) = server::run(Path::new(&args.model), bind, *port, device, 4) {
+            eprintln!("❌ Server error: {}", e);
+            process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(Command::Batch { input_dir, output_dir }) = &args.command {
+        run_batch(&args, input_dir, output_dir);
+        return;
+    }
+
+    let input = args.input.clone().unwrap_or_else(|| {
+        eprintln!("❌ INPUT is required when no subcommand is given");
+        process::exit(1);
+    });
+    let output = args.output.clone().unwrap_or_else(|| {
+        eprintln!("❌ OUTPUT is required when no subcommand is given");
+        process::exit(1);
+    });
+
     println!("🎨 rembg-rs - Background Removal Tool");
-    println!("Input: {:?}", args.input);
-    println!("Output: {:?}", args.output);
+    println!("Input: {:?}", input);
+    println!("Output: {:?}", output);
     println!("Model: {}", args.model);
     println!();
 
-    let mut manager = match ModelManager::from_file(Path::new(&args.model)) {
+    let device = parse_device(&args.device);
+    let mut manager = match ModelManager::from_file_with(Path::new(&args.model), device, 4) {
         Ok(m) => m,
         Err(e) => {
             eprintln!("❌ Failed to manager: {}", e);
@@ -29,7 +57,7 @@ fn main() {
 
     // Load image
     println!("📂 Loading image...");
-    let img = match open(&args.input) {
+    let img = match open(&input) {
         Ok(img) => img,
         Err(e) => {
             eprintln!("❌ Failed to load image: {}", e);
@@ -59,30 +87,68 @@ fn main() {
     // Save the result
     println!("💾 Saving result...");
     let result_img: DynamicImage = DynamicImage::ImageRgba8(result.image().clone());
-    if option_env!("NONE").is_none() {
-        match compress_png(&result_img) {
-            Ok(bytes) => match std::fs::write(&args.output, bytes) {
-                Ok(_) => {}
-                Err(e) => {
-                    eprintln!("❌ Writing failed: {e}");
-                    process::exit(1);
-                }
-            },
+
+    let format = match &args.format {
+        Some(fmt) => match fmt.to_ascii_lowercase().as_str() {
+            "png" => OutputFormat::Png,
+            "webp" => OutputFormat::WebP { lossless: args.webp_lossless },
+            "jpeg" | "jpg" => OutputFormat::Jpeg,
+            other => {
+                eprintln!("❌ Unknown output format: {other}");
+                process::exit(1);
+            }
+        },
+        None => {
+            let ext = output.extension().and_then(|s| s.to_str()).unwrap_or("png");
+            match OutputFormat::from_extension(ext) {
+                OutputFormat::WebP { .. } => OutputFormat::WebP { lossless: args.webp_lossless },
+                other => other,
+            }
+        }
+    };
+
+    let background = match &args.background {
+        Some(hex) => match formats::parse_hex_color(hex) {
+            Ok(rgb) => rgb,
             Err(e) => {
-                eprintln!("❌ Compression failed: {e}");
+                eprintln!("❌ {e}");
+                process::exit(1);
+            }
+        },
+        None => [255, 255, 255],
+    };
+
+    let compression = CompressionOptions {
+        opt_level: args.opt_level,
+        zopfli_iterations: args.zopfli.then_some(args.zopfli_iterations),
+        quant_quality: (args.quant_quality_min, args.quant_quality_max),
+        quant_speed: args.quant_speed,
+    };
+
+    // A 16-bit-per-channel source keeps its full depth through to here;
+    // only the PNG path can represent it, so fall through to the regular
+    // 8-bit pipeline for WebP/JPEG output.
+    let encoded = match (result.image16(), format) {
+        (Some(image16), OutputFormat::Png) => compress_png_16(image16, &compression),
+        _ => formats::encode(&result_img, format, background, &compression),
+    };
+
+    match encoded {
+        Ok(bytes) => {
+            if let Err(e) = std::fs::write(&output, bytes) {
+                eprintln!("❌ Writing failed: {e}");
                 process::exit(1);
             }
         }
-    } else {
-        if let Err(e) = result_img.save(&args.output) {
-            eprintln!("❌ Failed to save result: {}", e);
+        Err(e) => {
+            eprintln!("❌ Encoding failed: {e}");
             process::exit(1);
         }
     }
 
     // Save mask if requested
     if args.save_mask {
-        let mask_path = generate_mask_path(&args.output);
+        let mask_path = generate_mask_path(&output);
         println!("🎭 Saving mask to: {:?}", mask_path);
 
         // Save mask as transparent RGBA
@@ -94,12 +160,107 @@ fn main() {
 
     println!();
     println!("✅ Background removed successfully!");
-    println!("Output saved to: {:?}", args.output);
+    println!("Output saved to: {:?}", output);
     if args.save_mask {
         println!("🎭 Mask saved alongside output");
     }
 }
 
+/// Process every image in `input_dir` in parallel, writing results to
+/// `output_dir`, sharing one loaded `ModelManager` across threads.
+fn run_batch(args: &Args, input_dir: &Path, output_dir: &Path) {
+    let device = parse_device(&args.device);
+    let manager = match ModelManager::from_file_with(Path::new(&args.model), device, 4) {
+        Ok(m) => Arc::new(Mutex::new(m)),
+        Err(e) => {
+            eprintln!("❌ Failed to load model: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let options = RemovalOptionsBuilder::default()
+        .threshold(args.threshold)
+        .binary(args.binary)
+        .sticker(args.sticker)
+        .build()
+        .unwrap();
+
+    // Honor the same --format/--background/compression flags as the
+    // single-image CLI path instead of always writing default PNGs.
+    let format = match &args.format {
+        Some(fmt) => match fmt.to_ascii_lowercase().as_str() {
+            "png" => OutputFormat::Png,
+            "webp" => OutputFormat::WebP { lossless: args.webp_lossless },
+            "jpeg" | "jpg" => OutputFormat::Jpeg,
+            other => {
+                eprintln!("❌ Unknown output format: {other}");
+                process::exit(1);
+            }
+        },
+        None => OutputFormat::Png,
+    };
+    let background = match &args.background {
+        Some(hex) => match formats::parse_hex_color(hex) {
+            Ok(rgb) => rgb,
+            Err(e) => {
+                eprintln!("❌ {e}");
+                process::exit(1);
+            }
+        },
+        None => [255, 255, 255],
+    };
+    let compression = CompressionOptions {
+        opt_level: args.opt_level,
+        zopfli_iterations: args.zopfli.then_some(args.zopfli_iterations),
+        quant_quality: (args.quant_quality_min, args.quant_quality_max),
+        quant_speed: args.quant_speed,
+    };
+    let output = rembg_rs::batch::BatchOutput {
+        format,
+        background,
+        compression,
+    };
+
+    println!("🖼️  Processing {:?} -> {:?}...", input_dir, output_dir);
+
+    let results =
+        match rembg_rs::batch::process_directory(manager, input_dir, output_dir, &options, &output) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("❌ Batch processing failed: {}", e);
+                process::exit(1);
+            }
+        };
+
+    let mut failures = 0;
+    for (path, result) in &results {
+        if let Err(e) = result {
+            eprintln!("⚠️  {:?}: {}", path, e);
+            failures += 1;
+        }
+    }
+
+    println!(
+        "✅ Processed {}/{} images successfully",
+        results.len() - failures,
+        results.len()
+    );
+    if failures > 0 {
+        process::exit(1);
+    }
+}
+
+/// Map the `--device` CLI flag to an `ExecutionProvider`, defaulting to CPU
+/// for an unrecognized or absent value.
+fn parse_device(device: &str) -> ExecutionProvider {
+    match device.to_ascii_lowercase().as_str() {
+        "cuda" => ExecutionProvider::Cuda,
+        "coreml" => ExecutionProvider::CoreMl,
+        "directml" => ExecutionProvider::DirectMl,
+        _ => ExecutionProvider::Cpu,
+    }
+}
+
 /// Generate mask file path based on output path
 fn generate_mask_path(output_path: &Path) -> std::path::PathBuf {
     let file_stem = output_path
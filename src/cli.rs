@@ -0,0 +1,102 @@
+pub mod cli {
+    use clap::{Parser, Subcommand};
+    use std::path::PathBuf;
+
+    /// Command-line arguments for the `rembg-rs` binary.
+    #[derive(Parser, Debug)]
+    #[command(author, version, about = "Background removal tool", long_about = None)]
+    pub struct Args {
+        /// Input image path (ignored when a subcommand is given)
+        pub input: Option<PathBuf>,
+
+        /// Output image path (ignored when a subcommand is given)
+        pub output: Option<PathBuf>,
+
+        /// Path to the ONNX model file
+        #[arg(short, long, default_value = "model.onnx")]
+        pub model: String,
+
+        /// Mask threshold (0-255)
+        #[arg(short, long, default_value_t = 128)]
+        pub threshold: u8,
+
+        /// Use a hard binary cutoff instead of a soft alpha edge
+        #[arg(short, long)]
+        pub binary: bool,
+
+        /// Clean up sticker-style borders after compositing
+        #[arg(long)]
+        pub sticker: bool,
+
+        /// Also save the predicted mask alongside the output
+        #[arg(long)]
+        pub save_mask: bool,
+
+        /// ONNX Runtime execution provider to run inference on: cpu, cuda, coreml, directml
+        #[arg(long, default_value = "cpu")]
+        pub device: String,
+
+        /// Output image format: png, webp, jpeg. Defaults to the output file's extension.
+        #[arg(long)]
+        pub format: Option<String>,
+
+        /// Background color (as `#rrggbb`) composited behind JPEG output, which has no alpha
+        #[arg(long)]
+        pub background: Option<String>,
+
+        /// Encode WebP output losslessly instead of with lossy quality 90
+        #[arg(long)]
+        pub webp_lossless: bool,
+
+        /// oxipng optimization level, 0-6
+        #[arg(long, default_value_t = 4)]
+        pub opt_level: u8,
+
+        /// Use the slower-but-smaller Zopfli deflater instead of the default deflater
+        #[arg(long)]
+        pub zopfli: bool,
+
+        /// Zopfli iteration count, used only when --zopfli is set
+        #[arg(long, default_value_t = 15)]
+        pub zopfli_iterations: u8,
+
+        /// imagequant perceptual quality floor, 0-100
+        #[arg(long, default_value_t = 60)]
+        pub quant_quality_min: u8,
+
+        /// imagequant perceptual quality ceiling, 0-100
+        #[arg(long, default_value_t = 100)]
+        pub quant_quality_max: u8,
+
+        /// imagequant speed, 1 (best/slowest) to 10 (worst/fastest)
+        #[arg(long, default_value_t = 3)]
+        pub quant_speed: i32,
+
+        #[command(subcommand)]
+        pub command: Option<Command>,
+    }
+
+    /// Alternate run modes besides the default single-image conversion.
+    #[derive(Subcommand, Debug)]
+    pub enum Command {
+        /// Start an HTTP server exposing a `/remove` endpoint
+        Serve {
+            /// Address to bind the HTTP server to
+            #[arg(long, default_value = "127.0.0.1")]
+            bind: String,
+
+            /// Port to bind the HTTP server to
+            #[arg(long, default_value_t = 8080)]
+            port: u16,
+        },
+
+        /// Process every image in a directory in parallel
+        Batch {
+            /// Directory of input images
+            input_dir: PathBuf,
+
+            /// Directory to write processed output to
+            output_dir: PathBuf,
+        },
+    }
+}
@@ -1,8 +1,43 @@
 use crate::error::RembgError;
-use image::DynamicImage;
-use oxipng::{Options, StripChunks, optimize_from_memory};
+use image::{DynamicImage, ImageBuffer, Rgba};
+use oxipng::{Deflaters, Options, StripChunks, optimize_from_memory};
+use std::num::NonZeroU8;
+
+/// Tunable knobs for the PNG quantize+oxipng pipeline.
+///
+/// The defaults match what `compress_png` used to hard-code.
+#[derive(Debug, Clone)]
+pub struct CompressionOptions {
+    /// oxipng optimization level, 0-6.
+    pub opt_level: u8,
+    /// When `Some(iterations)`, use the slower-but-smaller Zopfli deflater
+    /// with that iteration count instead of the default deflater.
+    pub zopfli_iterations: Option<u8>,
+    /// imagequant perceptual quality floor/ceiling, each 0-100.
+    pub quant_quality: (u8, u8),
+    /// imagequant speed, 1 (best/slowest) to 10 (worst/fastest).
+    pub quant_speed: i32,
+}
+
+impl Default for CompressionOptions {
+    fn default() -> Self {
+        Self {
+            opt_level: 4,
+            zopfli_iterations: None,
+            quant_quality: (60, 100),
+            quant_speed: 3,
+        }
+    }
+}
 
 pub fn compress_png(image: &DynamicImage) -> Result<Vec<u8>, RembgError> {
+    compress_png_with(image, &CompressionOptions::default())
+}
+
+pub fn compress_png_with(
+    image: &DynamicImage,
+    compression: &CompressionOptions,
+) -> Result<Vec<u8>, RembgError> {
     // 1) RGBA8
     let rgba = image.to_rgba8();
     let (w, h) = rgba.dimensions();
@@ -21,8 +56,8 @@ pub fn compress_png(image: &DynamicImage) -> Result<Vec<u8>, RembgError> {
 
     // 3) Квантование (TinyPNG-стайл)
     let mut attr = imagequant::Attributes::new();
-    attr.set_quality(60, 100)?; // перцептуальное качество (0..100)
-    attr.set_speed(3)?; // 1 — лучше/медленнее, 10 — быстрее/хуже
+    attr.set_quality(compression.quant_quality.0, compression.quant_quality.1)?; // перцептуальное качество (0..100)
+    attr.set_speed(compression.quant_speed)?; // 1 — лучше/медленнее, 10 — быстрее/хуже
     let mut img = attr.new_image(pixels, w as usize, h as usize, 0.0)?; // 0.0 = sRGB :contentReference[oaicite:0]{index=0}
     let mut qres = attr.quantize(&mut img)?; // генерим палитру :contentReference[oaicite:1]{index=1}
 
@@ -51,10 +86,54 @@ pub fn compress_png(image: &DynamicImage) -> Result<Vec<u8>, RembgError> {
     }
 
     // 6) Lossless-оптимизация контейнера PNG (oxipng + zopfli)
-    let mut opt = Options::from_preset(4);
+    let mut opt = Options::from_preset(compression.opt_level);
     opt.strip = StripChunks::Safe;
     opt.optimize_alpha = true;
+    if let Some(iterations) = compression.zopfli_iterations {
+        let iterations = NonZeroU8::new(iterations.max(1)).unwrap_or(NonZeroU8::new(15).unwrap());
+        opt.deflate = Deflaters::Zopfli { iterations };
+    }
     let optimized = optimize_from_memory(&pal_png, &opt)?;
 
     Ok(optimized)
 }
+
+/// Write a 16-bit-per-channel RGBA image as a full-depth PNG.
+///
+/// Unlike `compress_png_with`, this skips `imagequant` quantization
+/// entirely — reducing to an 8-bit-indexed palette is exactly the precision
+/// loss a 16-bit output is meant to avoid — and only applies oxipng's
+/// lossless container optimization on top.
+pub fn compress_png_16(
+    image: &ImageBuffer<Rgba<u16>, Vec<u16>>,
+    compression: &CompressionOptions,
+) -> Result<Vec<u8>, RembgError> {
+    let (w, h) = image.dimensions();
+
+    let mut raw_png = Vec::new();
+    {
+        let mut enc = png::Encoder::new(&mut raw_png, w, h);
+        enc.set_color(png::ColorType::Rgba);
+        enc.set_depth(png::BitDepth::Sixteen);
+
+        let mut writer = enc.write_header()?;
+        // png expects 16-bit samples as big-endian bytes.
+        let mut be_bytes = Vec::with_capacity(image.as_raw().len() * 2);
+        for sample in image.as_raw() {
+            be_bytes.extend_from_slice(&sample.to_be_bytes());
+        }
+        writer.write_image_data(&be_bytes)?;
+        writer.finish()?;
+    }
+
+    let mut opt = Options::from_preset(compression.opt_level);
+    opt.strip = StripChunks::Safe;
+    opt.optimize_alpha = true;
+    if let Some(iterations) = compression.zopfli_iterations {
+        let iterations = NonZeroU8::new(iterations.max(1)).unwrap_or(NonZeroU8::new(15).unwrap());
+        opt.deflate = Deflaters::Zopfli { iterations };
+    }
+    let optimized = optimize_from_memory(&raw_png, &opt)?;
+
+    Ok(optimized)
+}
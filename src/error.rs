@@ -34,6 +34,13 @@ pub enum RembgError {
 
     /// Array shape error
     ShapeError(String),
+
+    /// JPEG encoding failed
+    JpegError(String),
+
+    /// A shared `Mutex` (e.g. around a `ModelManager`) was poisoned by a
+    /// panic on another thread
+    LockPoisoned(String),
 }
 
 impl fmt::Display for RembgError {
@@ -53,6 +60,8 @@ impl fmt::Display for RembgError {
             }
             RembgError::TensorError(op) => write!(f, "Tensor operation failed: {}", op),
             RembgError::ShapeError(msg) => write!(f, "Shape error: {}", msg),
+            RembgError::JpegError(msg) => write!(f, "JPEG encoding failed: {}", msg),
+            RembgError::LockPoisoned(msg) => write!(f, "Lock poisoned: {}", msg),
         }
     }
 }
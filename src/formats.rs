@@ -0,0 +1,101 @@
+// Selectable output formats. PNG keeps the existing quantize+oxipng path;
+// WebP and JPEG are encoded directly, with JPEG flattened over a solid
+// background since it has no alpha channel.
+
+use crate::compress_png::{CompressionOptions, compress_png_with};
+use crate::error::RembgError;
+use image::{DynamicImage, Rgb, RgbImage};
+
+/// Output image format for a processed cutout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Png,
+    WebP { lossless: bool },
+    Jpeg,
+}
+
+impl OutputFormat {
+    /// Infer the format from a file extension (case-insensitive), defaulting
+    /// to PNG for an unrecognized or missing extension.
+    pub fn from_extension(ext: &str) -> Self {
+        match ext.to_ascii_lowercase().as_str() {
+            "webp" => OutputFormat::WebP { lossless: false },
+            "jpg" | "jpeg" => OutputFormat::Jpeg,
+            _ => OutputFormat::Png,
+        }
+    }
+}
+
+/// Encode `image` (the RGBA result of `rembg`) in the requested format.
+/// `background` is only used for JPEG, which has no alpha channel.
+/// `compression` only affects the PNG path.
+pub fn encode(
+    image: &DynamicImage,
+    format: OutputFormat,
+    background: [u8; 3],
+    compression: &CompressionOptions,
+) -> Result<Vec<u8>, RembgError> {
+    match format {
+        OutputFormat::Png => compress_png_with(image, compression),
+        OutputFormat::WebP { lossless } => encode_webp(image, lossless),
+        OutputFormat::Jpeg => encode_jpeg(image, background),
+    }
+}
+
+fn encode_webp(image: &DynamicImage, lossless: bool) -> Result<Vec<u8>, RembgError> {
+    let rgba = image.to_rgba8();
+    let (w, h) = rgba.dimensions();
+    let encoder = webp::Encoder::from_rgba(rgba.as_raw(), w, h);
+
+    let memory = if lossless {
+        encoder.encode_lossless()
+    } else {
+        encoder.encode(90.0)
+    };
+
+    Ok(memory.to_vec())
+}
+
+fn encode_jpeg(image: &DynamicImage, background: [u8; 3]) -> Result<Vec<u8>, RembgError> {
+    let rgba = image.to_rgba8();
+    let (w, h) = rgba.dimensions();
+
+    let mut flattened = RgbImage::new(w, h);
+    for (x, y, px) in rgba.enumerate_pixels() {
+        let [r, g, b, a] = px.0;
+        let a = a as f32 / 255.0;
+        let blend = |fg: u8, bg: u8| (fg as f32 * a + bg as f32 * (1.0 - a)).round() as u8;
+        flattened.put_pixel(
+            x,
+            y,
+            Rgb([
+                blend(r, background[0]),
+                blend(g, background[1]),
+                blend(b, background[2]),
+            ]),
+        );
+    }
+
+    let mut bytes = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, 90)
+        .encode(flattened.as_raw(), w, h, image::ExtendedColorType::Rgb8)
+        .map_err(|e| RembgError::JpegError(e.to_string()))?;
+
+    Ok(bytes)
+}
+
+/// Parse a `#rrggbb` or `rrggbb` hex color into RGB components.
+pub fn parse_hex_color(s: &str) -> Result<[u8; 3], RembgError> {
+    let s = s.trim_start_matches('#');
+    if s.len() != 6 {
+        return Err(RembgError::InvalidInput(format!(
+            "invalid background color {:?}, expected 6 hex digits",
+            s
+        )));
+    }
+    let byte = |i: usize| {
+        u8::from_str_radix(&s[i..i + 2], 16)
+            .map_err(|_| RembgError::InvalidInput(format!("invalid background color {:?}", s)))
+    };
+    Ok([byte(0)?, byte(2)?, byte(4)?])
+}
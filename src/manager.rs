@@ -1,6 +1,10 @@
 use crate::error::RembgError;
 use ndarray::Array;
 use ort::{
+    execution_providers::{
+        CPUExecutionProvider, CUDAExecutionProvider, CoreMLExecutionProvider,
+        DirectMLExecutionProvider, ExecutionProvider as _,
+    },
     session::{
         Session,
         builder::{GraphOptimizationLevel, SessionBuilder},
@@ -9,6 +13,15 @@ use ort::{
 };
 use std::path::Path;
 
+/// ONNX Runtime execution provider to run inference on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionProvider {
+    Cpu,
+    Cuda,
+    CoreMl,
+    DirectMl,
+}
+
 pub struct ModelManager {
     session: Session,
 }
@@ -19,16 +32,67 @@ impl ModelManager {
     /// Uses memory mapping - OS decides whether to keep model in RAM or load on demand.
     /// This is the most memory-efficient approach for long-running applications.
     pub fn from_file(model_path: &Path) -> Result<Self, RembgError> {
-        // Create session with model file (uses memory mapping)
-        // In ort 2.0, environment is initialized automatically
-        let session = SessionBuilder::new()?
-            .with_optimization_level(GraphOptimizationLevel::Level3)?
-            .with_intra_threads(4)?
-            .commit_from_file(model_path)?;
+        Self::from_file_with(model_path, ExecutionProvider::Cpu, 4)
+    }
+
+    /// Create a model manager, registering `provider` as the ONNX Runtime
+    /// execution provider and using `intra_threads` intra-op threads.
+    ///
+    /// Falls back to the CPU provider (with a warning on stderr) if
+    /// `provider` fails to initialize, e.g. when the requested accelerator
+    /// or its runtime library isn't present on this machine. This keeps
+    /// existing CPU-only users unaffected by the new flag.
+    pub fn from_file_with(
+        model_path: &Path,
+        provider: ExecutionProvider,
+        intra_threads: usize,
+    ) -> Result<Self, RembgError> {
+        let build = |provider: ExecutionProvider| -> Result<Session, RembgError> {
+            let builder = SessionBuilder::new()?
+                .with_optimization_level(GraphOptimizationLevel::Level3)?
+                .with_intra_threads(intra_threads)?;
+            let builder = Self::register_provider(builder, provider)?;
+            Ok(builder.commit_from_file(model_path)?)
+        };
+
+        let session = match build(provider) {
+            Ok(session) => session,
+            Err(e) if provider != ExecutionProvider::Cpu => {
+                eprintln!(
+                    "⚠️  Failed to initialize {:?} execution provider ({}), falling back to CPU",
+                    provider, e
+                );
+                build(ExecutionProvider::Cpu)?
+            }
+            Err(e) => return Err(e),
+        };
 
         Ok(Self { session })
     }
 
+    fn register_provider(
+        builder: SessionBuilder,
+        provider: ExecutionProvider,
+    ) -> Result<SessionBuilder, RembgError> {
+        let builder = match provider {
+            ExecutionProvider::Cpu => {
+                builder.with_execution_providers([CPUExecutionProvider::default().build()])?
+            }
+            // `error_on_failure` makes a failed registration return an error
+            // (ort otherwise only logs it), so `from_file_with` can fall back.
+            ExecutionProvider::Cuda => builder.with_execution_providers([
+                CUDAExecutionProvider::default().build().error_on_failure(),
+            ])?,
+            ExecutionProvider::CoreMl => builder.with_execution_providers([
+                CoreMLExecutionProvider::default().build().error_on_failure(),
+            ])?,
+            ExecutionProvider::DirectMl => builder.with_execution_providers([
+                DirectMLExecutionProvider::default().build().error_on_failure(),
+            ])?,
+        };
+        Ok(builder)
+    }
+
     /// Run inference on preprocessed input
     pub fn run_inference(
         &mut self,
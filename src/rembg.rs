@@ -2,30 +2,60 @@ use crate::clean_sticker_border::clean_sticker_border;
 use crate::error::RembgError;
 use crate::manager::ModelManager;
 use crate::options::RemovalOptions;
+use crate::resize::Resizer;
 use crate::result::RemovalResult;
 use image::{DynamicImage, GenericImageView, ImageBuffer, Luma, RgbImage, Rgba, RgbaImage};
 use ndarray::{Array4, Axis};
 
+const MODEL_INPUT_SIZE: u32 = 320;
+
 pub fn rembg(
     manager: &mut ModelManager,
     image: DynamicImage,
     options: &RemovalOptions,
+) -> Result<RemovalResult, RembgError> {
+    let (width, height) = image.dimensions();
+    let mut resizer = Resizer::new(width, height, MODEL_INPUT_SIZE, MODEL_INPUT_SIZE);
+    rembg_with_resizer(manager, image, options, &mut resizer)
+}
+
+/// Same as [`rembg`], but resizes the 320x320 model input using a
+/// caller-supplied [`Resizer`] instead of building one per call.
+///
+/// Rebuilds `resizer` in place if `image`'s dimensions don't match the one
+/// it was built for, so callers that process a stream of same-sized images
+/// (e.g. batch directory processing) get the precomputed-taps benefit for
+/// free, while mixed-size input still behaves correctly.
+pub fn rembg_with_resizer(
+    manager: &mut ModelManager,
+    image: DynamicImage,
+    options: &RemovalOptions,
+    resizer: &mut Resizer,
 ) -> Result<RemovalResult, RembgError> {
     let (original_width, original_height) = image.dimensions();
 
+    // 16-bit-per-channel source images are kept at their native depth for
+    // the final composite, instead of being silently degraded by the
+    // `to_rgb8`/`to_rgba8` conversions used below for the (8-bit) model
+    // preprocessing and the 8-bit composite. Model inference itself
+    // still runs at its native 320x320/float32 resolution either way.
+    let is_16bit = matches!(
+        image,
+        DynamicImage::ImageRgba16(_)
+            | DynamicImage::ImageRgb16(_)
+            | DynamicImage::ImageLuma16(_)
+            | DynamicImage::ImageLumaA16(_)
+    );
+
     let preprocessed = {
         // Convert to RGB if not already
         let rgb_img = image.to_rgb8();
 
-        // Resize image
-        let target_width = 320;
-        let target_height = 320;
-        let resized = image::imageops::resize(
-            &rgb_img,
-            target_width,
-            target_height,
-            image::imageops::FilterType::Lanczos3,
-        );
+        if resizer.src_dims() != (original_width, original_height) {
+            *resizer = Resizer::new(original_width, original_height, MODEL_INPUT_SIZE, MODEL_INPUT_SIZE);
+        }
+        let resized = resizer.resize(&rgb_img);
+        let (target_width, target_height) = (MODEL_INPUT_SIZE, MODEL_INPUT_SIZE);
 
         // Convert to normalized float array with shape [1, 3, height, width]
         let mut array = Array4::<f32>::zeros((1, 3, target_height as usize, target_width as usize));
@@ -157,12 +187,98 @@ pub fn rembg(
         result
     };
 
+    // For 16-bit-per-channel sources, also build a 16-bit-color/16-bit-alpha
+    // composite so `compress_png` can emit a full-depth PNG. Sticker-border
+    // cleanup is an 8-bit-only postprocess and is skipped here; it still
+    // runs on the normal 8-bit `result_image` above.
+    if options.sticker && is_16bit {
+        eprintln!(
+            "⚠️  --sticker has no effect on 16-bit PNG output; the 16-bit image is written without border cleanup"
+        );
+    }
+    let image16 = if is_16bit {
+        Some(apply_mask_16bit(&image, &mask_output, options)?)
+    } else {
+        None
+    };
+
     Ok(RemovalResult {
         image: result_image,
         mask,
+        image16,
     })
 }
 
+/// 16-bit-color, 16-bit-alpha counterpart of the RGBA compositing step
+/// above, used when the source image is a 16-bit-per-channel integer image.
+fn apply_mask_16bit(
+    image: &DynamicImage,
+    mask_output: &Array4<f32>,
+    options: &RemovalOptions,
+) -> Result<ImageBuffer<Rgba<u16>, Vec<u16>>, RembgError> {
+    let rgba16 = image.to_rgba16();
+    let (width, height) = rgba16.dimensions();
+
+    let temp_axis = mask_output.index_axis(Axis(0), 0);
+    let mask_data = temp_axis.index_axis(Axis(0), 0);
+    let (model_h, model_w) = mask_data.dim();
+
+    let mut mask_gray: ImageBuffer<Luma<u16>, Vec<u16>> = ImageBuffer::new(model_w as u32, model_h as u32);
+    for (x, y, pixel) in mask_gray.enumerate_pixels_mut() {
+        let v = mask_data[[y as usize, x as usize]];
+        let s = 1.0 / (1.0 + (-v).exp());
+        pixel.0[0] = (s * 65535.0).clamp(0.0, 65535.0) as u16;
+    }
+
+    let need_resize = (model_w as u32 != width) || (model_h as u32 != height);
+    let mask_resized = if need_resize {
+        image::imageops::resize(
+            &mask_gray,
+            width,
+            height,
+            image::imageops::FilterType::Lanczos3,
+        )
+    } else {
+        mask_gray
+    };
+
+    let mut result = ImageBuffer::<Rgba<u16>, Vec<u16>>::new(width, height);
+    let thr_u16 = options.threshold as f32 * 257.0;
+    let smooth_scale = if options.threshold < 255 {
+        Some(65535.0 / (65535.0 - thr_u16))
+    } else {
+        None
+    };
+
+    for (x, y, src) in rgba16.enumerate_pixels() {
+        let mask_value = mask_resized.get_pixel(x, y).0[0] as f32;
+
+        let alpha: u16 = if options.binary {
+            if mask_value >= thr_u16 { 65535 } else { 0 }
+        } else {
+            match smooth_scale {
+                // Mirrors the 8-bit formula above (scaled to the 16-bit
+                // range) so switching output format doesn't change the
+                // shape of the soft edge.
+                Some(scale) => ((mask_value - thr_u16) * scale * 65535.0)
+                    .clamp(0.0, 65535.0)
+                    .round() as u16,
+                None => {
+                    if mask_value >= 65535.0 {
+                        65535
+                    } else {
+                        0
+                    }
+                }
+            }
+        };
+
+        result.put_pixel(x, y, Rgba([src.0[0], src.0[1], src.0[2], alpha]));
+    }
+
+    Ok(result)
+}
+
 // --- Inlined preprocessor/processor helpers ---
 
 #[inline]
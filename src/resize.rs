@@ -0,0 +1,153 @@
+// Reusable Lanczos3 resampler: precomputes per-axis taps once and reuses
+// them across frames instead of rebuilding them on every resize call.
+
+use image::RgbImage;
+
+const LANCZOS_A: f64 = 3.0;
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-12 {
+        1.0
+    } else {
+        let px = std::f64::consts::PI * x;
+        px.sin() / px
+    }
+}
+
+fn lanczos(x: f64) -> f64 {
+    if x.abs() >= LANCZOS_A {
+        0.0
+    } else {
+        sinc(x) * sinc(x / LANCZOS_A)
+    }
+}
+
+/// For each output sample along one axis: the starting source index and the
+/// normalized weights of its contributing source samples.
+struct AxisTaps {
+    start: Vec<usize>,
+    weights: Vec<Vec<f32>>,
+}
+
+fn build_axis_taps(src_len: u32, dst_len: u32) -> AxisTaps {
+    let src_len = src_len as usize;
+    let dst_len = dst_len as usize;
+    let scale = src_len as f64 / dst_len as f64;
+    // Widen the kernel when downsampling to keep it anti-aliased.
+    let filter_scale = scale.max(1.0);
+    let support = LANCZOS_A * filter_scale;
+
+    let mut start = Vec::with_capacity(dst_len);
+    let mut weights = Vec::with_capacity(dst_len);
+
+    for dst_x in 0..dst_len {
+        let center = (dst_x as f64 + 0.5) * scale;
+        let lo = ((center - support).floor() as isize).max(0) as usize;
+        let hi = ((center + support).ceil() as isize).min(src_len as isize - 1) as usize;
+
+        let mut taps = Vec::with_capacity(hi - lo + 1);
+        let mut sum = 0.0f64;
+        for src_x in lo..=hi {
+            let w = lanczos((src_x as f64 + 0.5 - center) / filter_scale);
+            taps.push(w);
+            sum += w;
+        }
+        if sum.abs() > 1e-12 {
+            for w in taps.iter_mut() {
+                *w /= sum;
+            }
+        }
+
+        start.push(lo);
+        weights.push(taps.into_iter().map(|w| w as f32).collect());
+    }
+
+    AxisTaps { start, weights }
+}
+
+/// A Lanczos3 resizer whose filter taps are precomputed for a fixed
+/// `(src_width, src_height) -> (dst_width, dst_height)` mapping and reused
+/// across every call to [`Resizer::resize`].
+pub struct Resizer {
+    src_width: u32,
+    src_height: u32,
+    dst_width: u32,
+    dst_height: u32,
+    horizontal: AxisTaps,
+    vertical: AxisTaps,
+    scratch: Vec<[f32; 3]>,
+}
+
+impl Resizer {
+    /// Build a resizer for images of `(src_width, src_height)` resized down
+    /// to `(dst_width, dst_height)`. Reuse the returned resizer only for
+    /// source images of that exact size; see [`Resizer::src_dims`].
+    pub fn new(src_width: u32, src_height: u32, dst_width: u32, dst_height: u32) -> Self {
+        let horizontal = build_axis_taps(src_width, dst_width);
+        let vertical = build_axis_taps(src_height, dst_height);
+        let scratch = vec![[0.0f32; 3]; dst_width as usize * src_height as usize];
+        Self {
+            src_width,
+            src_height,
+            dst_width,
+            dst_height,
+            horizontal,
+            vertical,
+            scratch,
+        }
+    }
+
+    /// The `(src_width, src_height)` this resizer was built for.
+    pub fn src_dims(&self) -> (u32, u32) {
+        (self.src_width, self.src_height)
+    }
+
+    /// Resize `src` (which must match `src_dims()`) into a freshly
+    /// allocated `dst_width x dst_height` image.
+    pub fn resize(&mut self, src: &RgbImage) -> RgbImage {
+        debug_assert_eq!((src.width(), src.height()), (self.src_width, self.src_height));
+
+        // Horizontal pass: src_width -> dst_width, rows unchanged.
+        for y in 0..self.src_height as usize {
+            for dst_x in 0..self.dst_width as usize {
+                let start = self.horizontal.start[dst_x];
+                let weights = &self.horizontal.weights[dst_x];
+                let mut acc = [0.0f32; 3];
+                for (i, &w) in weights.iter().enumerate() {
+                    let px = src.get_pixel((start + i) as u32, y as u32).0;
+                    acc[0] += px[0] as f32 * w;
+                    acc[1] += px[1] as f32 * w;
+                    acc[2] += px[2] as f32 * w;
+                }
+                self.scratch[y * self.dst_width as usize + dst_x] = acc;
+            }
+        }
+
+        // Vertical pass: src_height -> dst_height, columns already resized.
+        let mut out = RgbImage::new(self.dst_width, self.dst_height);
+        for dst_y in 0..self.dst_height as usize {
+            let start = self.vertical.start[dst_y];
+            let weights = &self.vertical.weights[dst_y];
+            for dst_x in 0..self.dst_width as usize {
+                let mut acc = [0.0f32; 3];
+                for (i, &w) in weights.iter().enumerate() {
+                    let px = self.scratch[(start + i) * self.dst_width as usize + dst_x];
+                    acc[0] += px[0] * w;
+                    acc[1] += px[1] * w;
+                    acc[2] += px[2] * w;
+                }
+                out.put_pixel(
+                    dst_x as u32,
+                    dst_y as u32,
+                    image::Rgb([
+                        acc[0].round().clamp(0.0, 255.0) as u8,
+                        acc[1].round().clamp(0.0, 255.0) as u8,
+                        acc[2].round().clamp(0.0, 255.0) as u8,
+                    ]),
+                );
+            }
+        }
+
+        out
+    }
+}
@@ -0,0 +1,29 @@
+use image::{ImageBuffer, Rgba, RgbaImage, RgbImage};
+
+/// Output of a single `rembg` call: the RGBA cutout, a visualization of the
+/// predicted mask, and (for 16-bit-per-channel sources) a full-depth RGBA16
+/// composite.
+pub struct RemovalResult {
+    pub(crate) image: RgbaImage,
+    pub(crate) mask: RgbImage,
+    pub(crate) image16: Option<ImageBuffer<Rgba<u16>, Vec<u16>>>,
+}
+
+impl RemovalResult {
+    /// The RGBA cutout at the source image's original dimensions.
+    pub fn image(&self) -> &RgbaImage {
+        &self.image
+    }
+
+    /// A heatmap visualization of the predicted mask.
+    pub fn mask(&self) -> &RgbImage {
+        &self.mask
+    }
+
+    /// The full 16-bit-per-channel RGBA composite, present only when the
+    /// source image was a 16-bit-per-channel integer image (32-bit float
+    /// sources go through the 8-bit path only).
+    pub fn image16(&self) -> Option<&ImageBuffer<Rgba<u16>, Vec<u16>>> {
+        self.image16.as_ref()
+    }
+}
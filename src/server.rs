@@ -0,0 +1,110 @@
+// HTTP server mode: loads the `ModelManager` once and reuses the warm ONNX
+// session across requests instead of paying its setup cost per invocation.
+
+use crate::compress_png::compress_png;
+use crate::error::RembgError;
+use crate::manager::{ExecutionProvider, ModelManager};
+use crate::options::RemovalOptionsBuilder;
+use crate::rembg::rembg;
+use actix_web::{
+    App, HttpResponse, HttpServer, ResponseError, http::StatusCode, web, web::PayloadConfig,
+};
+use image::DynamicImage;
+use serde::Deserialize;
+use std::path::Path;
+use std::sync::Mutex;
+
+struct AppState {
+    manager: Mutex<ModelManager>,
+}
+
+/// actix-web defaults `web::Bytes` extraction to a 256 KiB body limit, which
+/// real photos routinely exceed; raise it to a size generous enough for
+/// full-resolution images.
+const MAX_UPLOAD_BYTES: usize = 50 * 1024 * 1024;
+
+#[derive(Deserialize)]
+struct RemoveQuery {
+    threshold: Option<u8>,
+    binary: Option<bool>,
+    sticker: Option<bool>,
+    mask: Option<u8>,
+}
+
+impl ResponseError for RembgError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RembgError::InvalidInput(_)
+            | RembgError::UnsupportedFormat(_)
+            | RembgError::PreprocessingError(_)
+            | RembgError::ImageError(_)
+            | RembgError::ShapeError(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn remove(
+    state: web::Data<AppState>,
+    query: web::Query<RemoveQuery>,
+    body: web::Bytes,
+) -> Result<HttpResponse, RembgError> {
+    let image = image::load_from_memory(&body)?;
+
+    let options = RemovalOptionsBuilder::default()
+        .threshold(query.threshold.unwrap_or(128))
+        .binary(query.binary.unwrap_or(false))
+        .sticker(query.sticker.unwrap_or(false))
+        .build()
+        .map_err(|e| RembgError::InvalidInput(e.to_string()))?;
+
+    let result = {
+        let mut manager = state
+            .manager
+            .lock()
+            .map_err(|_| RembgError::LockPoisoned("model manager poisoned".to_string()))?;
+        rembg(&mut manager, image, &options)?
+    };
+
+    let out_img: DynamicImage = if query.mask.unwrap_or(0) != 0 {
+        DynamicImage::ImageRgb8(result.mask().clone())
+    } else {
+        DynamicImage::ImageRgba8(result.image().clone())
+    };
+
+    let bytes = compress_png(&out_img)?;
+    Ok(HttpResponse::Ok().content_type("image/png").body(bytes))
+}
+
+/// Start the HTTP server, loading `model_path` once (registering `provider`
+/// as the ONNX Runtime execution provider) and serving `/remove` requests
+/// against the shared, warm `ModelManager`.
+pub fn run(
+    model_path: &Path,
+    bind: &str,
+    port: u16,
+    provider: ExecutionProvider,
+    intra_threads: usize,
+) -> std::io::Result<()> {
+    let manager = ModelManager::from_file_with(model_path, provider, intra_threads)
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    let state = web::Data::new(AppState {
+        manager: Mutex::new(manager),
+    });
+
+    actix_web::rt::System::new().block_on(async move {
+        HttpServer::new(move || {
+            App::new()
+                .app_data(state.clone())
+                .app_data(PayloadConfig::new(MAX_UPLOAD_BYTES))
+                .route("/remove", web::post().to(remove))
+        })
+        .bind((bind, port))?
+        .run()
+        .await
+    })
+}